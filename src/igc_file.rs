@@ -0,0 +1,202 @@
+use crate::records::extension::ExtensionDefRecord;
+use crate::records::{
+    ARecord, BRecord, CRecordDeclaration, CRecordTurnpoint, GRecord, HRecord, KRecord, Record,
+};
+use crate::util::parse_error::ParseError;
+
+/// A task declaration, grouping the single `CRecordDeclaration` with the
+/// `turnpoint_count + 4` `CRecordTurnpoint`s that follow it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Task<'a> {
+    pub declaration: CRecordDeclaration<'a>,
+    pub turnpoints: Vec<CRecordTurnpoint<'a>>,
+}
+
+/// A parsed IGC file.
+///
+/// Where [`Record::parse_line`](crate::records::Record::parse_line) handles a
+/// single line at a time, `IgcFile` consumes a whole file and groups the
+/// records together: the C-record task declaration and its turnpoints are
+/// collected into a [`Task`], and the active I/J extension definitions are
+/// captured alongside the fixes and K records so callers can resolve add-on
+/// fields by mnemonic instead of working from a flat record stream.
+#[derive(Debug)]
+pub struct IgcFile<'a> {
+    pub a_record: Option<ARecord<'a>>,
+    pub headers: Vec<HRecord<'a>>,
+    pub fixes: Vec<BRecord>,
+    pub fix_extensions: Option<ExtensionDefRecord<'a>>,
+    pub task: Option<Task<'a>>,
+    pub k_extensions: Option<ExtensionDefRecord<'a>>,
+    pub k_records: Vec<KRecord>,
+    signed_data: &'a [u8],
+    signature: Vec<u8>,
+}
+
+impl<'a> IgcFile<'a> {
+    /// Parse an entire IGC file from its text contents.
+    pub fn parse(content: &'a str) -> Result<Self, ParseError> {
+        let mut file = IgcFile {
+            a_record: None,
+            headers: Vec::new(),
+            fixes: Vec::new(),
+            fix_extensions: None,
+            task: None,
+            k_extensions: None,
+            k_records: Vec::new(),
+            signed_data: &content.as_bytes()[..0],
+            signature: Vec::new(),
+        };
+
+        // Track the byte offset of the first G-record: everything before it,
+        // with the original terminators intact, is the signed payload.
+        let mut offset = 0;
+        let mut signed_len = content.len();
+
+        for raw in content.split_inclusive('\n') {
+            let start = offset;
+            offset += raw.len();
+
+            let line = raw.trim_end_matches(|c| c == '\r' || c == '\n');
+            if line.is_empty() {
+                continue;
+            }
+
+            match line.as_bytes()[0] {
+                // The I extension-definition record shares the I/J layout but
+                // has no dedicated `Record` variant, so parse it here.
+                b'I' => file.fix_extensions = Some(ExtensionDefRecord::parse(line)?),
+                b'G' => {
+                    if signed_len == content.len() {
+                        signed_len = start;
+                    }
+                    file.signature
+                        .extend_from_slice(GRecord::parse(line)?.data.as_bytes());
+                }
+                _ => match Record::parse_line(line)? {
+                    Record::A(a) => file.a_record = Some(a),
+                    Record::B(b) => file.fixes.push(b),
+                    Record::H(h) => file.headers.push(h),
+                    Record::CDeclaration(declaration) => {
+                        file.task = Some(Task { declaration, turnpoints: Vec::new() });
+                    }
+                    Record::CTurnpoint(turnpoint) => match file.task.as_mut() {
+                        Some(task) => task.turnpoints.push(turnpoint),
+                        // A turnpoint before its declaration is malformed per
+                        // the C-record grouping rule.
+                        None => return Err(ParseError::SyntaxError),
+                    },
+                    Record::J(j) => file.k_extensions = Some(j.0),
+                    Record::K(k) => file.k_records.push(k),
+                    Record::D(_) | Record::E(_) | Record::G(_) | Record::Unrecognised => {}
+                },
+            }
+        }
+
+        file.signed_data = &content.as_bytes()[..signed_len];
+
+        // A declared task must carry exactly `turnpoint_count + 4` turnpoints
+        // (the extra four being takeoff/landing and task start/finish), per
+        // the spec note in `c_record.rs`.
+        if let Some(task) = &file.task {
+            let expected = task.declaration.turnpoint_count as usize + 4;
+            if task.turnpoints.len() != expected {
+                return Err(ParseError::SyntaxError);
+            }
+        }
+
+        Ok(file)
+    }
+
+    /// Resolve a fix's optional add-on field by mnemonic, using the file's
+    /// active I-record extension definition.
+    ///
+    /// Returns `None` when no I record was present, the mnemonic is not
+    /// declared, or the fix line is too short to hold the declared columns;
+    /// otherwise the raw column substring from the fix line.
+    pub fn fix_extension<'b>(&'b self, fix: &'b BRecord, mnemonic: &str) -> Option<&'b str> {
+        fix.get_extension(mnemonic, self.fix_extensions.as_ref()?)
+    }
+
+    /// The canonical byte stream that the G-record signature covers: every
+    /// record line up to the first G record, with the original line
+    /// terminators preserved.
+    pub fn signed_data(&self) -> &[u8] {
+        self.signed_data
+    }
+
+    /// The raw signature bytes, concatenated across all G records.
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// Return the free-text value of the first header with the given mnemonic.
+    pub fn header(&self, mnemonic: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|h| h.mnemonic == mnemonic)
+            .map(|h| h.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A minimal task declares `turnpoint_count` 0, so it carries the four
+    // mandatory takeoff/start/finish/landing turnpoints and nothing more.
+    const SAMPLE: &str = "AXXXfoo\r\n\
+HFPLTPILOT:Buzz Lightyear\r\n\
+I013638ENL\r\n\
+C230718092044000000000200Foo task\r\n\
+C5156040N00038120WLBZ-Takeoff\r\n\
+C5156040N00038120WLBZ-Start\r\n\
+C5156040N00038120WLBZ-Finish\r\n\
+C5156040N00038120WLBZ-Landing\r\n\
+B1101355206343N00006198WA0058700558123\r\n\
+G0123456789ABCDEF\r\n";
+
+    #[test]
+    fn parses_a_whole_file() {
+        let file = IgcFile::parse(SAMPLE).unwrap();
+        assert!(file.a_record.is_some());
+        assert_eq!(file.header("PLT"), Some("Buzz Lightyear"));
+        assert_eq!(file.fixes.len(), 1);
+
+        let task = file.task.as_ref().unwrap();
+        assert_eq!(task.declaration.task_name, Some("Foo task"));
+        assert_eq!(task.turnpoints.len(), 4);
+    }
+
+    #[test]
+    fn resolves_fix_extension_by_mnemonic() {
+        let file = IgcFile::parse(SAMPLE).unwrap();
+        let fix = &file.fixes[0];
+        assert_eq!(file.fix_extension(fix, "ENL"), Some("123"));
+        assert_eq!(file.fix_extension(fix, "TAS"), None);
+    }
+
+    #[test]
+    fn rejects_wrong_turnpoint_count() {
+        // Declares two real turnpoints (count 2 → 6 expected) but supplies none.
+        let bad = "C230718092044000000000202Short task\r\n";
+        assert!(IgcFile::parse(bad).is_err());
+    }
+
+    #[test]
+    fn rejects_turnpoint_before_declaration() {
+        let bad = "C5156040N00038120WLBZ-Orphan\r\n";
+        assert!(IgcFile::parse(bad).is_err());
+    }
+
+    #[test]
+    fn captures_signature_and_signed_payload() {
+        let file = IgcFile::parse(SAMPLE).unwrap();
+        assert_eq!(file.signature(), b"0123456789ABCDEF");
+
+        // The signed payload is everything up to (but not including) the G line.
+        let signed = file.signed_data();
+        assert!(signed.ends_with(b"B1101355206343N00006198WA0058700558123\r\n"));
+        assert!(!std::str::from_utf8(signed).unwrap().contains("G0123456789"));
+    }
+}
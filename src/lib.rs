@@ -0,0 +1,4 @@
+pub mod records;
+pub mod util;
+
+pub mod igc_file;
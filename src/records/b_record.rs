@@ -0,0 +1,106 @@
+use crate::records::extension::ExtensionDefRecord;
+use crate::util::coord::RawPosition;
+use crate::util::datetime::Time;
+use crate::util::parse_error::ParseError;
+
+/// Whether a B-record fix carries a valid 3D GPS position.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FixValid {
+    Valid,
+    NavWarning,
+}
+
+/// A B (fix) record: a single timestamped position along the flight.
+///
+/// The line has the form `B<hhmmss><lat><lon><valid><palt><galt>` followed by
+/// any optional add-on fields whose columns are declared by the preceding
+/// I record. The source line is retained so those extensions can be resolved
+/// by mnemonic through [`get_extension`](BRecord::get_extension).
+#[derive(Debug, PartialEq, Eq)]
+pub struct BRecord {
+    pub timestamp: Time,
+    pub position: RawPosition,
+    pub fix_valid: FixValid,
+    pub pressure_alt: i16,
+    pub gps_alt: i16,
+    pub raw: String,
+}
+
+impl BRecord {
+    /// Parse a string as a B record.
+    ///
+    /// ```
+    /// # extern crate igc_rs;
+    /// # use igc_rs::{ records::BRecord, util::Time };
+    /// let record = BRecord::parse("B1101355206343N00006198WA0058700558").unwrap();
+    /// assert_eq!(record.timestamp, Time::from_hms(11, 01, 35));
+    /// assert_eq!(record.pressure_alt, 587);
+    /// assert_eq!(record.gps_alt, 558);
+    /// ```
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        assert!(line.len() >= 35);
+        assert!(line.as_bytes()[0] == b'B');
+
+        let timestamp = Time::parse(&line[1..7])?;
+        let position = RawPosition::parse_lat_lon(&line[7..24])?;
+        let fix_valid = match line.as_bytes()[24] {
+            b'A' => FixValid::Valid,
+            b'V' => FixValid::NavWarning,
+            _ => return Err(ParseError::SyntaxError),
+        };
+        let pressure_alt = line[25..30].parse::<i16>()?;
+        let gps_alt = line[30..35].parse::<i16>()?;
+
+        Ok(BRecord {
+            timestamp,
+            position,
+            fix_valid,
+            pressure_alt,
+            gps_alt,
+            raw: line.to_string(),
+        })
+    }
+
+    /// Look up an optional add-on field by its mnemonic, returning the raw
+    /// substring of the fix line between the 1-based `start` and `end` columns
+    /// given by the matching I-record extension definition.
+    ///
+    /// ```
+    /// # extern crate igc_rs;
+    /// # use igc_rs::records::{BRecord, IRecord};
+    /// let def = IRecord::parse("I013638ENL").unwrap();
+    /// let record = BRecord::parse("B1101355206343N00006198WA0058700558123").unwrap();
+    /// assert_eq!(record.get_extension("ENL", &def.0), Some("123"));
+    /// assert_eq!(record.get_extension("TAS", &def.0), None);
+    /// ```
+    pub fn get_extension(&self, mnemonic: &str, def: &ExtensionDefRecord) -> Option<&str> {
+        def.extensions
+            .iter()
+            .find(|ext| ext.mnemonic == mnemonic)
+            .and_then(|ext| self.raw.get(ext.start as usize - 1..ext.end as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::records::IRecord;
+
+    #[test]
+    fn b_record_parse() {
+        let parsed = BRecord::parse("B1101355206343N00006198WA0058700558").unwrap();
+        assert_eq!(parsed.timestamp, Time::from_hms(11, 01, 35));
+        assert_eq!(parsed.fix_valid, FixValid::Valid);
+        assert_eq!(parsed.pressure_alt, 587);
+        assert_eq!(parsed.gps_alt, 558);
+    }
+
+    #[test]
+    fn b_record_get_extension() {
+        // I record declares a single "ENL" field in columns 36..=38
+        let def = IRecord::parse("I013638ENL").unwrap();
+        let parsed = BRecord::parse("B1101355206343N00006198WA0058700558123").unwrap();
+        assert_eq!(parsed.get_extension("ENL", &def.0), Some("123"));
+        assert_eq!(parsed.get_extension("TAS", &def.0), None);
+    }
+}
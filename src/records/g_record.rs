@@ -0,0 +1,39 @@
+use crate::util::parse_error::ParseError;
+
+/// A G record, holding one line of the file's digital signature.
+///
+/// The signature may span several consecutive `G` lines at the end of the
+/// file; each line contributes its bytes (everything after the leading `G`) to
+/// the complete signature. The records preceding the first G record form the
+/// signed payload.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GRecord<'a> {
+    pub data: &'a str,
+}
+
+impl<'a> GRecord<'a> {
+    /// Parse a string as a G record.
+    ///
+    /// ```
+    /// # extern crate igc_rs;
+    /// # use igc_rs::records::GRecord;
+    /// let record = GRecord::parse("GABCDEF0123456789").unwrap();
+    /// assert_eq!(record.data, "ABCDEF0123456789");
+    /// ```
+    pub fn parse(line: &'a str) -> Result<Self, ParseError> {
+        assert!(line.as_bytes()[0] == b'G');
+
+        Ok(GRecord { data: &line[1..] })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn g_record_parse() {
+        let parsed = GRecord::parse("GABCDEF0123456789").unwrap();
+        assert_eq!(parsed.data, "ABCDEF0123456789");
+    }
+}
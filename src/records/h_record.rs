@@ -0,0 +1,101 @@
+use crate::util::ParseError;
+
+/// The data source of an H (header) record.
+///
+/// The byte immediately following the `H` identifies who or what produced the
+/// header field: the flight recorder unit itself, an observer/official, or the
+/// pilot.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DataSource {
+    FlightRecorder,
+    Observer,
+    Pilot,
+}
+
+impl DataSource {
+    fn parse(byte: u8) -> Result<Self, ParseError> {
+        match byte {
+            b'F' => Ok(DataSource::FlightRecorder),
+            b'O' => Ok(DataSource::Observer),
+            b'P' => Ok(DataSource::Pilot),
+            _ => Err(ParseError::SyntaxError),
+        }
+    }
+}
+
+/// A header record, describing a single piece of flight metadata.
+///
+/// Header lines have the form `H<source><mnemonic><long name>:<data>`, e.g.
+/// `HFPLTPILOT:Buzz Lightyear`. The three-byte mnemonic (`PLT`, `GTY`, `GID`,
+/// `DTE`, `FTY`, `RFW`, …) names the field, the optional human-readable label
+/// runs up to the first `:`, and the free-text value follows it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct HRecord<'a> {
+    pub data_source: DataSource,
+    pub mnemonic: &'a str,
+    pub friendly_name: Option<&'a str>,
+    pub data: &'a str,
+}
+
+impl<'a> HRecord<'a> {
+    /// Parse a string as an H record.
+    ///
+    /// ```
+    /// # extern crate igc_rs;
+    /// # use igc_rs::records::{HRecord, DataSource};
+    /// let record = HRecord::parse("HFPLTPILOT:Buzz Lightyear").unwrap();
+    /// assert_eq!(record.data_source, DataSource::FlightRecorder);
+    /// assert_eq!(record.mnemonic, "PLT");
+    /// assert_eq!(record.friendly_name, Some("PILOT"));
+    /// assert_eq!(record.data, "Buzz Lightyear");
+    /// ```
+    pub fn parse(line: &'a str) -> Result<Self, ParseError> {
+        assert!(line.len() >= 5);
+        assert!(line.as_bytes()[0] == b'H');
+
+        let data_source = DataSource::parse(line.as_bytes()[1])?;
+        let mnemonic = &line[2..5];
+
+        let (friendly_name, data) = match line[5..].find(':') {
+            Some(colon) => {
+                let label = &line[5..5 + colon];
+                let friendly_name = if label.is_empty() { None } else { Some(label) };
+                (friendly_name, &line[5 + colon + 1..])
+            }
+            None => (None, &line[5..]),
+        };
+
+        Ok(HRecord { data_source, mnemonic, friendly_name, data })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn h_record_parse() {
+        let parsed = HRecord::parse("HFPLTPILOT:Buzz Lightyear").unwrap();
+        let expected = HRecord {
+            data_source: DataSource::FlightRecorder,
+            mnemonic: "PLT",
+            friendly_name: Some("PILOT"),
+            data: "Buzz Lightyear",
+        };
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn h_record_parse_without_friendly_name() {
+        let parsed = HRecord::parse("HPGTY:Ka6").unwrap();
+        assert_eq!(parsed.data_source, DataSource::Pilot);
+        assert_eq!(parsed.mnemonic, "GTY");
+        assert_eq!(parsed.friendly_name, None);
+        assert_eq!(parsed.data, "Ka6");
+    }
+
+    #[test]
+    fn h_record_parse_rejects_unknown_data_source() {
+        assert!(HRecord::parse("HXGID:foo").is_err());
+    }
+}
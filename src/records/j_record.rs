@@ -0,0 +1,42 @@
+use std::fmt;
+
+use crate::records::extension::ExtensionDefRecord;
+use crate::util::ParseError;
+
+/// A J record, declaring the column layout of the periodic K records.
+///
+/// It shares the `I<num><start><end><mnemonic>…` layout of the I record, but
+/// annotates K records rather than B records.
+#[derive(Debug, PartialEq, Eq)]
+pub struct JRecord<'a>(pub ExtensionDefRecord<'a>);
+
+impl<'a> JRecord<'a> {
+    pub fn parse(line: &'a str) -> Result<Self, ParseError> {
+        let first_byte = line.as_bytes()[0];
+        assert!(first_byte == b'J');
+        Ok(JRecord(ExtensionDefRecord::parse(line)?))
+    }
+}
+
+impl<'a> fmt::Display for JRecord<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f, 'J')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::records::extension::Extension;
+
+    #[test]
+    fn jrecord_format() {
+        let expected_string = "J010812HDT";
+        let record = JRecord(ExtensionDefRecord {
+            num_extensions: 1,
+            extensions: vec![Extension::new("HDT", 8, 12)],
+        });
+
+        assert_eq!(format!("{}", record), expected_string);
+    }
+}
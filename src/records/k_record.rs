@@ -0,0 +1,75 @@
+use crate::records::JRecord;
+use crate::util::datetime::Time;
+use crate::util::parse_error::ParseError;
+
+/// A periodically-logged data record.
+///
+/// K records carry a timestamp followed by a run of data fields whose meaning
+/// is declared by the preceding [`JRecord`](super::JRecord), much as I records
+/// describe the optional fields of B records. The line has the form
+/// `K<hhmmss><data…>`; the raw line is retained so the declared fields can be
+/// sliced out by column.
+#[derive(Debug, PartialEq, Eq)]
+pub struct KRecord {
+    pub time: Time,
+    pub raw: String,
+}
+
+impl KRecord {
+    /// Parse a string as a K record.
+    ///
+    /// ```
+    /// # extern crate igc_rs;
+    /// # use igc_rs::{ records::KRecord, util::Time };
+    /// let record = KRecord::parse("K0920440210").unwrap();
+    /// assert_eq!(record.time, Time::from_hms(9, 20, 44));
+    /// ```
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        assert!(line.len() >= 7);
+        assert!(line.as_bytes()[0] == b'K');
+
+        let time = Time::parse(&line[1..7])?;
+
+        Ok(KRecord { time, raw: line.to_string() })
+    }
+
+    /// Look up a declared field by its mnemonic, returning the raw substring of
+    /// the K line between the 1-based `start` and `end` columns given by the
+    /// matching J-record extension definition.
+    ///
+    /// ```
+    /// # extern crate igc_rs;
+    /// # use igc_rs::records::{JRecord, KRecord};
+    /// let def = JRecord::parse("J010812HDT").unwrap();
+    /// let record = KRecord::parse("K092044").unwrap();
+    /// // no data logged in this short sample
+    /// assert_eq!(record.get_extension("HDT", &def), None);
+    /// ```
+    pub fn get_extension(&self, mnemonic: &str, def: &JRecord) -> Option<&str> {
+        def.0.extensions
+            .iter()
+            .find(|ext| ext.mnemonic == mnemonic)
+            .and_then(|ext| self.raw.get(ext.start as usize - 1..ext.end as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn k_record_parse() {
+        let parsed = KRecord::parse("K0920440210").unwrap();
+        assert_eq!(parsed.time, Time::from_hms(9, 20, 44));
+        assert_eq!(parsed.raw, "K0920440210");
+    }
+
+    #[test]
+    fn k_record_get_extension() {
+        // J record declares a single "OAT" field in columns 8..=11
+        let def = JRecord::parse("J010811OAT").unwrap();
+        let parsed = KRecord::parse("K0920440210").unwrap();
+        assert_eq!(parsed.get_extension("OAT", &def), Some("0210"));
+        assert_eq!(parsed.get_extension("TAS", &def), None);
+    }
+}
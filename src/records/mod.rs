@@ -5,12 +5,20 @@ mod b_record;
 mod c_record;
 mod d_record;
 mod e_record;
+mod g_record;
+mod h_record;
+mod j_record;
+mod k_record;
 
 pub use self::a_record::*;
 pub use self::b_record::BRecord;
 pub use self::c_record::{CRecordDeclaration,CRecordTurnpoint};
 pub use self::d_record::DRecord;
 pub use self::e_record::ERecord;
+pub use self::g_record::GRecord;
+pub use self::h_record::{DataSource,HRecord};
+pub use self::j_record::JRecord;
+pub use self::k_record::KRecord;
 
 #[derive(Debug)]
 pub enum Record<'a> {
@@ -20,6 +28,10 @@ pub enum Record<'a> {
     CTurnpoint (CRecordTurnpoint<'a>),
     D (DRecord<'a>),
     E (ERecord<'a>),
+    G (GRecord<'a>),
+    H (HRecord<'a>),
+    J (JRecord<'a>),
+    K (KRecord),
     Unrecognised,
 }
 
@@ -40,6 +52,10 @@ impl<'a> Record<'a> {
             },
             b'D' => Record::D(DRecord::parse(line)?),
             b'E' => Record::E(ERecord::parse(line)?),
+            b'G' => Record::G(GRecord::parse(line)?),
+            b'H' => Record::H(HRecord::parse(line)?),
+            b'J' => Record::J(JRecord::parse(line)?),
+            b'K' => Record::K(KRecord::parse(line)?),
             _ => Record::Unrecognised,
         };
 